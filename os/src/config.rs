@@ -0,0 +1,15 @@
+//! Constants used in rCore
+//! 内核的全局常量：应用槽位几何、栈大小与系统调用上限
+
+/// 用户栈大小
+pub const USER_STACK_SIZE: usize = 4096 * 2;
+/// 内核栈大小
+pub const KERNEL_STACK_SIZE: usize = 4096 * 2;
+/// 最大 app 数量
+pub const MAX_APP_NUM: usize = 16;
+/// 应用程序基址
+pub const APP_BASE_ADDRESS: usize = 0x80400000;
+/// 每个应用程序的大小限制，同时也是相邻槽位之间的间距
+pub const APP_SIZE_LIMIT: usize = 0x20000;
+/// 记录系统调用次数时支持的最大 syscall id 数量
+pub const MAX_SYSCALL_NUM: usize = 500;
@@ -0,0 +1,108 @@
+//! Loading user applications into memory
+//! 一次性把所有应用加载到各自独立的槽位，支持多道程序常驻
+
+use crate::config::{
+    APP_BASE_ADDRESS, APP_SIZE_LIMIT, KERNEL_STACK_SIZE, MAX_APP_NUM, USER_STACK_SIZE,
+};
+use crate::trap::TrapContext;
+use core::arch::asm;
+
+#[repr(align(4096))]
+#[derive(Copy, Clone)]
+struct KernelStack {
+    data: [u8; KERNEL_STACK_SIZE],
+}
+
+#[repr(align(4096))]
+#[derive(Copy, Clone)]
+struct UserStack {
+    data: [u8; USER_STACK_SIZE],
+}
+
+//每个常驻应用各自拥有一个内核栈和用户栈
+static KERNEL_STACK: [KernelStack; MAX_APP_NUM] = [KernelStack {
+    data: [0; KERNEL_STACK_SIZE],
+}; MAX_APP_NUM];
+
+static USER_STACK: [UserStack; MAX_APP_NUM] = [UserStack {
+    data: [0; USER_STACK_SIZE],
+}; MAX_APP_NUM];
+
+impl KernelStack {
+    fn get_sp(&self) -> usize {
+        self.data.as_ptr() as usize + KERNEL_STACK_SIZE
+    }
+    pub fn push_context(&self, cx: TrapContext) -> usize {
+        let cx_ptr = (self.get_sp() - core::mem::size_of::<TrapContext>()) as *mut TrapContext;
+        unsafe {
+            *cx_ptr = cx;
+        }
+        cx_ptr as usize
+    }
+}
+
+impl UserStack {
+    fn get_sp(&self) -> usize {
+        self.data.as_ptr() as usize + USER_STACK_SIZE
+    }
+}
+
+/// 返回 app_id 对应的加载基址
+pub fn get_base_i(app_id: usize) -> usize {
+    APP_BASE_ADDRESS + app_id * APP_SIZE_LIMIT
+}
+
+/// 读取链接进内核镜像的应用数量
+pub fn get_num_app() -> usize {
+    extern "C" {
+        fn _num_app();
+    }
+    unsafe { (_num_app as usize as *const usize).read_volatile() }
+}
+
+/// 一次性把所有应用加载到各自独立的、互不重叠的槽位
+pub fn load_apps() {
+    extern "C" {
+        fn _num_app();
+    }
+    let num_app_ptr = _num_app as usize as *const usize;
+    let num_app = get_num_app();
+    let app_start = unsafe { core::slice::from_raw_parts(num_app_ptr.add(1), num_app + 1) };
+    // 依次把每个应用拷贝到它自己的槽位
+    for i in 0..num_app {
+        let base_i = get_base_i(i);
+        // clear app area
+        (base_i..base_i + APP_SIZE_LIMIT).for_each(|addr| unsafe {
+            (addr as *mut u8).write_volatile(0);
+        });
+        let src = unsafe {
+            core::slice::from_raw_parts(app_start[i] as *const u8, app_start[i + 1] - app_start[i])
+        };
+        let dst = unsafe { core::slice::from_raw_parts_mut(base_i as *mut u8, src.len()) };
+        dst.copy_from_slice(src);
+    }
+    // 所有拷贝完成后统一执行一次 fence.i
+    unsafe {
+        asm!("fence.i");
+    }
+}
+
+/// 返回 app_id 所在应用区间 `[base, base + APP_SIZE_LIMIT)`
+pub fn get_app_range(app_id: usize) -> (usize, usize) {
+    let base = get_base_i(app_id);
+    (base, base + APP_SIZE_LIMIT)
+}
+
+/// 返回 app_id 用户栈所在区间 `[bottom, top)`
+pub fn get_user_stack_range(app_id: usize) -> (usize, usize) {
+    let bottom = USER_STACK[app_id].data.as_ptr() as usize;
+    (bottom, bottom + USER_STACK_SIZE)
+}
+
+/// 构建 app_id 的初始陷入上下文，并压入其内核栈，返回内核栈上的地址
+pub fn init_app_cx(app_id: usize) -> usize {
+    KERNEL_STACK[app_id].push_context(TrapContext::app_init_context(
+        get_base_i(app_id),
+        USER_STACK[app_id].get_sp(),
+    ))
+}
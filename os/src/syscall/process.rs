@@ -1,7 +1,10 @@
 //! Process management syscalls
 use crate::{
     config::MAX_SYSCALL_NUM,
-    task::{exit_current_and_run_next, suspend_current_and_run_next, TaskStatus},
+    task::{
+        exit_current_and_run_next, get_current_task_info, suspend_current_and_run_next,
+        user_ptr_valid, TaskStatus,
+    },
     timer::get_time_us,
 };
 
@@ -40,6 +43,9 @@ pub fn sys_yield() -> isize { //让当前任务放弃CPU资源
 /// get time with second and microsecond
 pub fn sys_get_time(ts: *mut TimeVal, _tz: usize) -> isize {
     trace!("kernel: sys_get_time");
+    if !user_ptr_valid(ts as usize, core::mem::size_of::<TimeVal>()) {
+        return -1;
+    }
     let us = get_time_us();
     unsafe {
         *ts = TimeVal {
@@ -65,10 +71,16 @@ pub fn sys_get_time(ts: *mut TimeVal, _tz: usize) -> isize {
 /// YOUR JOB: Finish sys_task_info to pass testcases
 pub fn sys_task_info(ti: *mut TaskInfo) -> isize {
     trace!("kernel: sys_task_info");
+    if !user_ptr_valid(ti as usize, core::mem::size_of::<TaskInfo>()) {
+        return -1;
+    }
+    let (status, syscall_times, time) = get_current_task_info();
     unsafe {
-        if let TaskStatus::UnInit = (*ti).status {
-            return -1;
-        }
-        0
+        *ti = TaskInfo {
+            status,
+            syscall_times,
+            time,
+        };
     }
+    0
 }
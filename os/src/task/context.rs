@@ -0,0 +1,36 @@
+//! Task context
+//! 任务上下文：切换时需要保存/恢复的寄存器
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TaskContext {
+    /// 返回地址 ra
+    ra: usize,
+    /// 内核栈指针 sp
+    sp: usize,
+    /// 被调用者保存寄存器 s0~s11
+    s: [usize; 12],
+}
+
+impl TaskContext {
+    /// 构造一个全零的任务上下文
+    pub fn zero_init() -> Self {
+        Self {
+            ra: 0,
+            sp: 0,
+            s: [0; 12],
+        }
+    }
+
+    /// 构造一个初次运行的任务上下文：ra 指向 __restore，sp 指向该任务内核栈栈顶
+    pub fn goto_restore(kstack_ptr: usize) -> Self {
+        extern "C" {
+            fn __restore();
+        }
+        Self {
+            ra: __restore as usize,
+            sp: kstack_ptr,
+            s: [0; 12],
+        }
+    }
+}
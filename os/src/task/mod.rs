@@ -0,0 +1,212 @@
+//! Task management implementation
+//! 多道程序的任务管理与协作式调度
+
+mod context;
+mod switch;
+
+use crate::config::{MAX_APP_NUM, MAX_SYSCALL_NUM};
+use crate::loader::{get_app_range, get_num_app, get_user_stack_range, init_app_cx};
+use crate::sync::UPSafeCell;
+use crate::timer::get_time_ms;
+use context::TaskContext;
+use lazy_static::*;
+use switch::__switch;
+
+/// 任务状态
+#[derive(Copy, Clone, PartialEq)]
+pub enum TaskStatus {
+    /// uninitialized
+    UnInit,
+    /// ready to run
+    Ready,
+    /// running
+    Running,
+    /// exited
+    Exited,
+}
+
+/// 任务控制块：保存任务状态与任务上下文
+#[derive(Copy, Clone)]
+pub struct TaskControlBlock {
+    /// 任务当前所处的状态
+    pub task_status: TaskStatus,
+    /// 任务上下文，切换时保存/恢复
+    pub task_cx: TaskContext,
+    /// 该任务各系统调用的调用次数，按 syscall id 索引
+    pub syscall_times: [u32; MAX_SYSCALL_NUM],
+    /// 任务首次被调度运行的时刻（ms），0 表示尚未运行
+    pub first_run_time: usize,
+}
+
+/// 任务管理器，负责所有常驻任务的调度
+pub struct TaskManager {
+    /// 常驻任务数量
+    num_app: usize,
+    /// 可变部分放入 UPSafeCell 以获得内部可变性
+    inner: UPSafeCell<TaskManagerInner>,
+}
+
+struct TaskManagerInner {
+    /// 每个任务的任务控制块
+    tasks: [TaskControlBlock; MAX_APP_NUM],
+    /// 当前正在运行的任务下标
+    current_task: usize,
+}
+
+lazy_static! {
+    /// 全局任务管理器实例
+    pub static ref TASK_MANAGER: TaskManager = {
+        let num_app = get_num_app();
+        let mut tasks = [TaskControlBlock {
+            task_cx: TaskContext::zero_init(),
+            task_status: TaskStatus::UnInit,
+            syscall_times: [0; MAX_SYSCALL_NUM],
+            first_run_time: 0,
+        }; MAX_APP_NUM];
+        for (i, task) in tasks.iter_mut().enumerate().take(num_app) {
+            task.task_cx = TaskContext::goto_restore(init_app_cx(i));
+            task.task_status = TaskStatus::Ready;
+        }
+        TaskManager {
+            num_app,
+            inner: unsafe {
+                UPSafeCell::new(TaskManagerInner {
+                    tasks,
+                    current_task: 0,
+                })
+            },
+        }
+    };
+}
+
+impl TaskManager {
+    /// 运行第一个任务：从 __switch 切入，永不返回
+    fn run_first_task(&self) -> ! {
+        let mut inner = self.inner.exclusive_access();
+        let task0 = &mut inner.tasks[0];
+        task0.task_status = TaskStatus::Running;
+        if task0.first_run_time == 0 {
+            task0.first_run_time = get_time_ms();
+        }
+        let next_task_cx_ptr = &task0.task_cx as *const TaskContext;
+        drop(inner);
+        let mut _unused = TaskContext::zero_init();
+        unsafe {
+            __switch(&mut _unused as *mut TaskContext, next_task_cx_ptr);
+        }
+        panic!("unreachable in run_first_task!");
+    }
+
+    /// 标记当前任务为挂起（Ready）
+    fn mark_current_suspended(&self) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].task_status = TaskStatus::Ready;
+    }
+
+    /// 标记当前任务为已退出
+    fn mark_current_exited(&self) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        inner.tasks[current].task_status = TaskStatus::Exited;
+    }
+
+    /// 记录当前任务对某个系统调用的一次调用
+    fn record_syscall(&self, syscall_id: usize) {
+        let mut inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        if syscall_id < MAX_SYSCALL_NUM {
+            inner.tasks[current].syscall_times[syscall_id] += 1;
+        }
+    }
+
+    /// 返回当前任务的状态、系统调用计数以及已运行时间（ms）
+    fn get_current_task_info(&self) -> (TaskStatus, [u32; MAX_SYSCALL_NUM], usize) {
+        let inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        let task = &inner.tasks[current];
+        (
+            task.task_status,
+            task.syscall_times,
+            get_time_ms() - task.first_run_time,
+        )
+    }
+
+    /// 校验用户指针：要求 `[ptr, ptr + len)` 完整落在当前任务自己的应用区间或用户栈内
+    fn user_ptr_valid(&self, ptr: usize, len: usize) -> bool {
+        let current = self.inner.exclusive_access().current_task;
+        // 防止加法溢出
+        let end = match ptr.checked_add(len) {
+            Some(end) => end,
+            None => return false,
+        };
+        let (app_lo, app_hi) = get_app_range(current);
+        let (stk_lo, stk_hi) = get_user_stack_range(current);
+        (ptr >= app_lo && end <= app_hi) || (ptr >= stk_lo && end <= stk_hi)
+    }
+
+    /// 从 current+1 开始扫描，找到下一个就绪任务
+    fn find_next_task(&self) -> Option<usize> {
+        let inner = self.inner.exclusive_access();
+        let current = inner.current_task;
+        (current + 1..current + self.num_app + 1)
+            .map(|id| id % self.num_app)
+            .find(|id| inner.tasks[*id].task_status == TaskStatus::Ready)
+    }
+
+    /// 切换到下一个就绪任务；若没有则说明所有任务均已结束
+    fn run_next_task(&self) {
+        if let Some(next) = self.find_next_task() {
+            let mut inner = self.inner.exclusive_access();
+            let current = inner.current_task;
+            inner.tasks[next].task_status = TaskStatus::Running;
+            if inner.tasks[next].first_run_time == 0 {
+                inner.tasks[next].first_run_time = get_time_ms();
+            }
+            inner.current_task = next;
+            let current_task_cx_ptr = &mut inner.tasks[current].task_cx as *mut TaskContext;
+            let next_task_cx_ptr = &inner.tasks[next].task_cx as *const TaskContext;
+            drop(inner);
+            unsafe {
+                __switch(current_task_cx_ptr, next_task_cx_ptr);
+            }
+            // 回到此处说明该任务再次被调度
+        } else {
+            println!("All applications completed!");
+            use crate::board::QEMUExit;
+            crate::board::QEMU_EXIT_HANDLE.exit_success();
+        }
+    }
+}
+
+/// 运行第一个任务
+pub fn run_first_task() {
+    TASK_MANAGER.run_first_task();
+}
+
+/// 挂起当前任务并运行下一个任务
+pub fn suspend_current_and_run_next() {
+    TASK_MANAGER.mark_current_suspended();
+    TASK_MANAGER.run_next_task();
+}
+
+/// 退出当前任务并运行下一个任务
+pub fn exit_current_and_run_next() {
+    TASK_MANAGER.mark_current_exited();
+    TASK_MANAGER.run_next_task();
+}
+
+/// 校验 `[ptr, ptr + len)` 是否落在当前任务合法的用户地址范围内
+pub fn user_ptr_valid(ptr: usize, len: usize) -> bool {
+    TASK_MANAGER.user_ptr_valid(ptr, len)
+}
+
+/// 记录当前任务的一次系统调用
+pub fn record_syscall(syscall_id: usize) {
+    TASK_MANAGER.record_syscall(syscall_id);
+}
+
+/// 获取当前任务的状态、系统调用计数与已运行时间
+pub fn get_current_task_info() -> (TaskStatus, [u32; MAX_SYSCALL_NUM], usize) {
+    TASK_MANAGER.get_current_task_info()
+}
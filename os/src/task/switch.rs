@@ -0,0 +1,11 @@
+//! Rust entry for the assembly context switch routine
+
+use super::context::TaskContext;
+use core::arch::global_asm;
+
+global_asm!(include_str!("switch.S"));
+
+extern "C" {
+    /// 保存当前任务上下文到 current_task_cx_ptr，并切换到 next_task_cx_ptr
+    pub fn __switch(current_task_cx_ptr: *mut TaskContext, next_task_cx_ptr: *const TaskContext);
+}
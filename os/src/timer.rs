@@ -0,0 +1,30 @@
+//! RISC-V timer-related functionality
+//! 读取时钟、换算时间以及设置下一次时钟中断
+
+use crate::board::CLOCK_FREQ;
+use crate::sbi::set_timer;
+use riscv::register::time;
+
+const TICKS_PER_SEC: usize = 100; //每秒 100 次时钟中断
+const MSEC_PER_SEC: usize = 1000;
+const MICRO_PER_SEC: usize = 1_000_000;
+
+/// 读取 mtime 计数器当前值
+pub fn get_time() -> usize {
+    time::read()
+}
+
+/// 以毫秒为单位返回当前时间
+pub fn get_time_ms() -> usize {
+    time::read() / (CLOCK_FREQ / MSEC_PER_SEC)
+}
+
+/// 以微秒为单位返回当前时间
+pub fn get_time_us() -> usize {
+    time::read() / (CLOCK_FREQ / MICRO_PER_SEC)
+}
+
+/// 设置下一次时钟中断的触发时刻
+pub fn set_next_trigger() {
+    set_timer(get_time() + CLOCK_FREQ / TICKS_PER_SEC);
+}
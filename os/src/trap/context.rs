@@ -0,0 +1,34 @@
+//! Trap context
+//! 保存陷入内核时的用户态寄存器上下文
+
+use riscv::register::sstatus::{self, Sstatus, SPP};
+
+#[repr(C)]
+pub struct TrapContext {
+    /// 通用寄存器 x0~x31
+    pub x: [usize; 32],
+    /// 陷入前的 sstatus
+    pub sstatus: Sstatus,
+    /// 陷入前的 sepc
+    pub sepc: usize,
+}
+
+impl TrapContext {
+    /// 设置栈指针 x2(sp)
+    pub fn set_sp(&mut self, sp: usize) {
+        self.x[2] = sp;
+    }
+    /// 构造应用首次进入用户态的上下文
+    pub fn app_init_context(entry: usize, sp: usize) -> Self {
+        let mut sstatus = sstatus::read();
+        // 返回用户态运行
+        sstatus.set_spp(SPP::User);
+        let mut cx = Self {
+            x: [0; 32],
+            sstatus,
+            sepc: entry, // 入口地址
+        };
+        cx.set_sp(sp); // 用户栈指针
+        cx
+    }
+}
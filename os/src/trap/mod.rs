@@ -0,0 +1,70 @@
+//! Trap handling functionality
+//! 陷入处理：系统调用、异常以及时钟中断驱动的抢占式调度
+
+mod context;
+
+use crate::syscall::syscall;
+use crate::task::{exit_current_and_run_next, suspend_current_and_run_next};
+use crate::timer::set_next_trigger;
+use core::arch::global_asm;
+use riscv::register::{
+    scause::{self, Exception, Interrupt, Trap},
+    sie, sstatus, stval, stvec,
+    utvec::TrapMode,
+};
+
+global_asm!(include_str!("trap.S"));
+
+/// 初始化陷入入口，将 stvec 指向 __alltraps
+pub fn init() {
+    extern "C" {
+        fn __alltraps();
+    }
+    unsafe {
+        stvec::write(__alltraps as usize, TrapMode::Direct);
+    }
+}
+
+/// 开启 S 态时钟中断（sie.STIE），并在 sstatus.SIE 中全局使能中断
+pub fn enable_timer_interrupt() {
+    unsafe {
+        sie::set_stimer();
+        sstatus::set_sie();
+    }
+}
+
+#[no_mangle]
+/// handle an interrupt, exception, or system call from user space
+pub fn trap_handler(cx: &mut TrapContext) -> &mut TrapContext {
+    let scause = scause::read();
+    let stval = stval::read();
+    match scause.cause() {
+        Trap::Exception(Exception::UserEnvCall) => {
+            cx.sepc += 4;
+            cx.x[10] = syscall(cx.x[17], [cx.x[10], cx.x[11], cx.x[12]]) as usize;
+        }
+        Trap::Exception(Exception::StoreFault) | Trap::Exception(Exception::StorePageFault) => {
+            println!("[kernel] PageFault in application, kernel killed it.");
+            exit_current_and_run_next();
+        }
+        Trap::Exception(Exception::IllegalInstruction) => {
+            println!("[kernel] IllegalInstruction in application, kernel killed it.");
+            exit_current_and_run_next();
+        }
+        // S 态时钟中断：重置下一次触发并轮转到下一个就绪任务
+        Trap::Interrupt(Interrupt::SupervisorTimer) => {
+            set_next_trigger();
+            suspend_current_and_run_next();
+        }
+        _ => {
+            panic!(
+                "Unsupported trap {:?}, stval = {:#x}!",
+                scause.cause(),
+                stval
+            );
+        }
+    }
+    cx
+}
+
+pub use context::TrapContext;